@@ -0,0 +1,40 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Member};
+
+/// Generates the implementation of the `Bundle` trait for the provided type.
+pub fn derive(mut input: DeriveInput) -> TokenStream {
+    let Data::Struct(data) = &input.data else {
+        return syn::Error::new_spanned(&input.ident, "`Bundle` can only be derived for structs")
+            .into_compile_error();
+    };
+
+    let types = data.fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+    let members = data.fields.members().collect::<Vec<Member>>();
+
+    crate::add_static_bounds(&mut input.generics);
+    let where_clause = input.generics.make_where_clause();
+    for ty in &types {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: ::sage_ecs::component::Bundle));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        unsafe impl #impl_generics ::sage_ecs::component::Bundle for #ident #ty_generics #where_clause {
+            fn register_components(
+                registry: &mut ::sage_ecs::component::Registry,
+                out: &mut ::sage_ecs::__private::Vec<::sage_ecs::component::ComponentId>,
+            ) {
+                #(<#types as ::sage_ecs::component::Bundle>::register_components(registry, out);)*
+            }
+
+            unsafe fn take_components(self, dst: &mut impl ::core::ops::FnMut() -> *mut u8) {
+                #(unsafe { ::sage_ecs::component::Bundle::take_components(self.#members, dst) };)*
+            }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+/// Generates the implementation of the `Component` trait for the provided type.
+pub fn derive(mut input: DeriveInput) -> TokenStream {
+    crate::add_static_bounds(&mut input.generics);
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::sage_ecs::component::Component for #ident #ty_generics #where_clause {}
+    }
+}
@@ -0,0 +1,87 @@
+//! Derive macros for the Sage game engine.
+//!
+//! The macros of this crate are re-exported by the crates that define the derived traits. They
+//! should not be used directly through this crate.
+
+use proc_macro::TokenStream;
+use syn::{parse_quote, Generics};
+
+mod bundle;
+mod component;
+
+/// Implements the `Component` trait for the annotated type.
+///
+/// # Examples
+///
+/// ```
+/// use sage_ecs::component::{Component, Registry};
+///
+/// #[derive(Component)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(Component)]
+/// struct Wrap<T>(T);
+///
+/// let mut registry = Registry::new();
+/// registry.register_rust_component::<Position>();
+/// registry.register_rust_component::<Wrap<u32>>();
+/// ```
+#[proc_macro_derive(Component)]
+pub fn derive_component(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    self::component::derive(input).into()
+}
+
+/// Implements the `Bundle` trait for the annotated struct.
+///
+/// Every field of the struct must itself be a bundle (usually a component). Fields are
+/// registered and inserted in declaration order, and nested bundles are flattened.
+///
+/// # Examples
+///
+/// ```
+/// use sage_ecs::component::{Bundle, Component, Registry};
+///
+/// #[derive(Component)]
+/// struct Position(f32, f32);
+///
+/// #[derive(Component)]
+/// struct Velocity(f32, f32);
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(Bundle)]
+/// struct PlayerBundle {
+///     position: Position,
+///     velocity: Velocity,
+///     player: Player,
+/// }
+///
+/// let mut registry = Registry::new();
+/// let id = registry.register_rust_bundle::<PlayerBundle>();
+/// assert_eq!(registry.bundles()[id].components.len(), 3);
+/// ```
+#[proc_macro_derive(Bundle)]
+pub fn derive_bundle(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    self::bundle::derive(input).into()
+}
+
+/// Adds a `'static` bound to every type parameter of the provided generics.
+///
+/// The derived traits all require `'static`, which generic types only satisfy when their type
+/// parameters do.
+fn add_static_bounds(generics: &mut Generics) {
+    let params = generics
+        .type_params()
+        .map(|p| p.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause.predicates.push(parse_quote!(#param: 'static));
+    }
+}
@@ -0,0 +1,127 @@
+//! Performs random sequences of operations on a [`Column`] and checks that its elements are
+//! preserved and dropped exactly once.
+
+#![no_main]
+
+use std::{
+    alloc::Layout,
+    cell::Cell,
+    ptr::{read, write},
+};
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use sage_ecs::tables::Column;
+
+#[derive(Debug, Arbitrary)]
+enum Op {
+    /// Reserves capacity for additional elements.
+    Reserve(u8),
+    /// Pushes a number of elements at once.
+    Push(u8),
+    /// Removes all elements from the column.
+    Clear,
+}
+
+#[derive(Debug, Arbitrary)]
+enum Element {
+    /// An element that takes some space in memory.
+    Sized,
+    /// A zero-sized element with a non-trivial alignment.
+    ZeroSized,
+}
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    element: Element,
+    needs_drop: bool,
+    ops: Vec<Op>,
+}
+
+thread_local! {
+    static DROPPED: Cell<Vec<u64>> = const { Cell::new(Vec::new()) };
+}
+
+/// The drop function for sized elements. Records the value of the dropped element.
+unsafe fn drop_sized(p: *mut u8) {
+    let value = unsafe { read(p as *const u64) };
+    DROPPED.with(|d| {
+        let mut v = d.take();
+        v.push(value);
+        d.set(v);
+    });
+}
+
+/// The drop function for zero-sized elements.
+unsafe fn drop_zero_sized(_: *mut u8) {
+    DROPPED.with(|d| {
+        let mut v = d.take();
+        v.push(0);
+        d.set(v);
+    });
+}
+
+fn dropped() -> Vec<u64> {
+    DROPPED.with(|d| d.take())
+}
+
+fuzz_target!(|input: Input| {
+    let (layout, drop_fn): (Layout, unsafe fn(*mut u8)) = match input.element {
+        Element::Sized => (Layout::new::<u64>(), drop_sized),
+        Element::ZeroSized => (Layout::new::<[u64; 0]>(), drop_zero_sized),
+    };
+    let zero_sized = layout.size() == 0;
+    let value_at = |i: usize| if zero_sized { 0 } else { i as u64 + 1 };
+
+    let mut column = Column::new(layout, input.needs_drop.then_some(drop_fn as _));
+    dropped();
+
+    for op in input.ops {
+        match op {
+            Op::Reserve(additional) => {
+                column.reserve(additional as usize);
+                assert!(column.spare_capacity() >= additional as usize);
+            }
+            Op::Push(count) => {
+                let count = count as usize;
+                column.reserve(count);
+                let len = column.len();
+                for i in len..len + count {
+                    if !zero_sized {
+                        unsafe { write(column.get_unchecked_mut(i) as *mut u64, value_at(i)) };
+                    }
+                }
+                unsafe { column.assume_init_push(count) };
+                assert_eq!(column.len(), len + count);
+            }
+            Op::Clear => {
+                let len = column.len();
+                column.clear();
+                assert!(column.is_empty());
+
+                let expected = if input.needs_drop {
+                    (0..len).map(value_at).collect()
+                } else {
+                    Vec::new()
+                };
+                assert_eq!(dropped(), expected);
+            }
+        }
+
+        assert!(column.len() <= column.capacity());
+        assert_eq!(column.spare_capacity(), column.capacity() - column.len());
+        assert_eq!(column.as_ptr() as usize % layout.align(), 0);
+        if !zero_sized {
+            for i in 0..column.len() {
+                assert_eq!(
+                    unsafe { read(column.get_unchecked(i) as *const u64) },
+                    value_at(i)
+                );
+            }
+        }
+    }
+
+    let len = column.len();
+    drop(column);
+    assert_eq!(dropped().len(), if input.needs_drop { len } else { 0 });
+});
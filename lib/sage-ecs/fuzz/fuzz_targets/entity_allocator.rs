@@ -0,0 +1,137 @@
+//! Performs random sequences of operations on an [`EntityAllocator`] and checks them against a
+//! simple model of the live and reserved entities.
+
+#![no_main]
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use sage_ecs::entity::{Entity, EntityAllocator};
+
+#[derive(Debug, Arbitrary)]
+enum Op {
+    /// Allocates an entity with the provided metadata.
+    Allocate(u32),
+    /// Deallocates the n-th live entity.
+    Deallocate(usize),
+    /// Deallocates an entity that was previously removed.
+    DeallocateDead(usize),
+    /// Reserves a single entity.
+    ReserveOne,
+    /// Reserves multiple entities, iterating over the result in two steps.
+    ReserveMultiple { count: u8, consumed: u8 },
+    /// Flushes the reserved entities.
+    Flush(u32),
+}
+
+/// The expected state of the allocator.
+#[derive(Default)]
+struct Model {
+    /// The entities that are currently live, along with their metadata.
+    live: BTreeMap<Entity, u32>,
+    /// The entities that have been reserved but not flushed yet.
+    reserved: BTreeSet<Entity>,
+    /// Entities that have been deallocated.
+    dead: Vec<Entity>,
+}
+
+impl Model {
+    /// Ensures that a newly reserved or allocated entity does not alias any entity that is
+    /// still in use.
+    fn check_fresh(&self, entity: Entity) {
+        assert!(!self.live.contains_key(&entity), "{entity} is already live");
+        assert!(
+            !self.reserved.contains(&entity),
+            "{entity} is already reserved"
+        );
+        assert!(
+            self.live
+                .keys()
+                .chain(&self.reserved)
+                .all(|e| e.index() != entity.index()),
+            "index of {entity} is already in use",
+        );
+    }
+}
+
+fn flush(allocator: &mut EntityAllocator<u32>, model: &mut Model, metadata: u32) {
+    assert_eq!(allocator.reserved(), model.reserved.len());
+    assert_eq!(allocator.needs_flush(), !model.reserved.is_empty());
+
+    let mut flushed = BTreeSet::new();
+    allocator.flush(|entity| {
+        assert!(flushed.insert(entity), "{entity} was flushed twice");
+        metadata
+    });
+
+    assert_eq!(
+        flushed, model.reserved,
+        "flushed entities do not match reserved ones"
+    );
+    model.live.extend(
+        core::mem::take(&mut model.reserved)
+            .into_iter()
+            .map(|e| (e, metadata)),
+    );
+
+    assert_eq!(allocator.reserved(), 0);
+    assert!(!allocator.needs_flush());
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut allocator = EntityAllocator::<u32>::new();
+    let mut model = Model::default();
+
+    for op in ops {
+        match op {
+            Op::Allocate(metadata) => {
+                flush(&mut allocator, &mut model, metadata);
+                let entity = allocator.allocate(metadata);
+                model.check_fresh(entity);
+                model.live.insert(entity, metadata);
+            }
+            Op::Deallocate(n) => {
+                flush(&mut allocator, &mut model, 0);
+                let Some(&entity) = model.live.keys().nth(n % model.live.len().max(1)) else {
+                    continue;
+                };
+                assert!(allocator.deallocate(entity));
+                model.live.remove(&entity);
+                model.dead.push(entity);
+            }
+            Op::DeallocateDead(n) => {
+                flush(&mut allocator, &mut model, 0);
+                let Some(&entity) = model.dead.get(n % model.dead.len().max(1)) else {
+                    continue;
+                };
+                assert!(!allocator.deallocate(entity));
+            }
+            Op::ReserveOne => {
+                let entity = allocator.reserve_one();
+                model.check_fresh(entity);
+                model.reserved.insert(entity);
+            }
+            Op::ReserveMultiple { count, consumed } => {
+                let mut iter = allocator.reserve_multiple(count as usize);
+                let mut entities = iter.by_ref().take(consumed as usize).collect::<Vec<_>>();
+                entities.extend(iter);
+                assert_eq!(entities.len(), count as usize);
+                for entity in entities {
+                    model.check_fresh(entity);
+                    model.reserved.insert(entity);
+                }
+            }
+            Op::Flush(metadata) => flush(&mut allocator, &mut model, metadata),
+        }
+
+        assert_eq!(allocator.count(), model.live.len());
+        assert_eq!(allocator.reserved(), model.reserved.len());
+        for (&entity, metadata) in &model.live {
+            assert_eq!(allocator.get(entity), Some(metadata));
+        }
+        for &entity in &model.dead {
+            assert_eq!(allocator.get(entity), None);
+        }
+    }
+});
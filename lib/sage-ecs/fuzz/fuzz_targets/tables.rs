@@ -0,0 +1,211 @@
+//! Spawns random Rust bundles into [`Tables`] and checks the locations, stored values and drop
+//! counts of their components.
+
+#![no_main]
+
+use std::{cell::Cell, collections::BTreeMap, rc::Rc};
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use sage_ecs::{
+    component::{Component, ComponentId, Registry},
+    tables::{EntityLocation, TableId, Tables},
+};
+
+struct A(u32);
+struct B(u64);
+struct D(u32, Rc<Cell<usize>>);
+struct Z;
+
+impl Component for A {}
+impl Component for B {}
+impl Component for D {}
+impl Component for Z {}
+
+impl Drop for D {
+    fn drop(&mut self) {
+        self.1.set(self.1.get() + 1);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Arbitrary)]
+enum Kind {
+    Empty,
+    A,
+    AB,
+    BA,
+    D,
+    ADZ,
+    Z,
+    Nested,
+}
+
+#[derive(Debug, Arbitrary)]
+enum Op {
+    /// Spawns a bundle of the provided kind through [`Tables::spawn`].
+    Spawn(Kind, u32),
+    /// Spawns an entity through [`Tables::spawn_empty`].
+    SpawnEmpty,
+    /// Reserves memory in the table of an entity that was previously spawned.
+    Reserve(usize, u8),
+}
+
+/// The component IDs registered in the fuzzed [`Registry`].
+struct Ids {
+    a: ComponentId,
+    b: ComponentId,
+    d: ComponentId,
+    z: ComponentId,
+}
+
+impl Kind {
+    /// Returns the sorted set of components that make up this kind of bundle.
+    fn components(self, ids: &Ids) -> Vec<ComponentId> {
+        let mut components = match self {
+            Kind::Empty => vec![],
+            Kind::A => vec![ids.a],
+            Kind::AB | Kind::BA => vec![ids.a, ids.b],
+            Kind::D => vec![ids.d],
+            Kind::ADZ => vec![ids.a, ids.d, ids.z],
+            Kind::Z => vec![ids.z],
+            Kind::Nested => vec![ids.a, ids.b, ids.d, ids.z],
+        };
+        components.sort_unstable();
+        components
+    }
+
+    /// Returns whether this kind of bundle contains a `D` component.
+    fn has_d(self) -> bool {
+        matches!(self, Kind::D | Kind::ADZ | Kind::Nested)
+    }
+}
+
+/// An entity that was spawned in the tables.
+struct Spawned {
+    location: EntityLocation,
+    components: Vec<ComponentId>,
+    value: u32,
+}
+
+/// Spawns a bundle of the provided kind.
+fn spawn(
+    tables: &mut Tables<usize>,
+    registry: &mut Registry,
+    kind: Kind,
+    metadata: usize,
+    value: u32,
+    drops: &Rc<Cell<usize>>,
+) -> EntityLocation {
+    let d = || D(value, drops.clone());
+    let b = || B(value as u64 * 3);
+
+    // SAFETY: The same registry is used for every call.
+    unsafe {
+        match kind {
+            Kind::Empty => tables.spawn(registry, metadata, ()),
+            Kind::A => tables.spawn(registry, metadata, A(value)),
+            Kind::AB => tables.spawn(registry, metadata, (A(value), b())),
+            Kind::BA => tables.spawn(registry, metadata, (b(), A(value))),
+            Kind::D => tables.spawn(registry, metadata, d()),
+            Kind::ADZ => tables.spawn(registry, metadata, (A(value), d(), Z)),
+            Kind::Z => tables.spawn(registry, metadata, Z),
+            Kind::Nested => tables.spawn(registry, metadata, ((b(), Z), (d(), (A(value),)))),
+        }
+    }
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut registry = Registry::new();
+    let ids = Ids {
+        a: registry.register_rust_component::<A>(),
+        b: registry.register_rust_component::<B>(),
+        d: registry.register_rust_component::<D>(),
+        z: registry.register_rust_component::<Z>(),
+    };
+
+    let mut tables = Tables::<usize>::new();
+    let mut spawned = Vec::<Spawned>::new();
+    let mut table_ids = BTreeMap::<Vec<ComponentId>, TableId>::new();
+    let mut table_lens = BTreeMap::<TableId, usize>::new();
+    let mut expected_drops = 0;
+    let drops = Rc::new(Cell::new(0));
+
+    table_ids.insert(Vec::new(), 0);
+
+    for op in ops {
+        let (location, components, value) = match op {
+            Op::Spawn(kind, value) => {
+                let metadata = spawned.len();
+                let location = spawn(&mut tables, &mut registry, kind, metadata, value, &drops);
+                if kind.has_d() {
+                    expected_drops += 1;
+                }
+                (location, kind.components(&ids), value)
+            }
+            Op::SpawnEmpty => (tables.spawn_empty(spawned.len()), Vec::new(), 0),
+            Op::Reserve(n, additional) => {
+                if let Some(entity) = spawned.get(n % spawned.len().max(1)) {
+                    // SAFETY: The table of a spawned entity always exists.
+                    unsafe { tables.reserve(entity.location.table_id, additional as usize) };
+                }
+                continue;
+            }
+        };
+
+        let table_id = *table_ids
+            .entry(components.clone())
+            .or_insert(location.table_id);
+        assert_eq!(location.table_id, table_id, "{components:?} changed tables");
+
+        let len = table_lens.entry(table_id).or_insert(0);
+        assert_eq!(
+            location.table_row, *len,
+            "row is not at the end of the table"
+        );
+        *len += 1;
+
+        spawned.push(Spawned {
+            location,
+            components,
+            value,
+        });
+
+        for (metadata, entity) in spawned.iter().enumerate() {
+            let EntityLocation {
+                table_id,
+                table_row,
+            } = entity.location;
+            let table = tables.get(table_id).unwrap();
+
+            assert_eq!(table.len(), table_lens[&table_id]);
+            assert_eq!(table.components(), &*entity.components);
+            assert_eq!(table.metadata()[table_row], metadata);
+
+            // SAFETY: Every column of the table has been initialized up to its length, which
+            // includes `table_row`.
+            unsafe {
+                if let Some(column) = table.column(ids.a) {
+                    let a = &*column.get_unchecked(table_row).cast::<A>();
+                    assert_eq!(a.0, entity.value);
+                }
+                if let Some(column) = table.column(ids.b) {
+                    let b = &*column.get_unchecked(table_row).cast::<B>();
+                    assert_eq!(b.0, entity.value as u64 * 3);
+                }
+                if let Some(column) = table.column(ids.d) {
+                    let d = &*column.get_unchecked(table_row).cast::<D>();
+                    assert_eq!(d.0, entity.value);
+                }
+            }
+        }
+
+        assert_eq!(drops.get(), 0, "a component was dropped while stored");
+    }
+
+    drop(tables);
+    assert_eq!(
+        drops.get(),
+        expected_drops,
+        "stored components were not dropped"
+    );
+});
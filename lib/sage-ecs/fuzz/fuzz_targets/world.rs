@@ -0,0 +1,62 @@
+//! Reserves and flushes entities in an [`UnsafeWorld`] and checks that every entity ends up in
+//! its own row of the table reported by its location.
+
+#![no_main]
+
+use std::collections::HashSet;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use sage_ecs::{entity::Entity, tables::EntityLocation, world::UnsafeWorld};
+
+#[derive(Debug, Arbitrary)]
+enum Op {
+    /// Reserves a number of entities.
+    Reserve(u8),
+    /// Flushes the reserved entities.
+    Flush,
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut world = UnsafeWorld::new();
+    let mut live = Vec::<Entity>::new();
+    let mut reserved = Vec::<Entity>::new();
+
+    for op in ops {
+        match op {
+            Op::Reserve(count) => {
+                for _ in 0..count {
+                    let entity = world.reserve_one();
+                    assert_eq!(world.location(entity), None, "{entity} has a location");
+                    reserved.push(entity);
+                }
+            }
+            Op::Flush => {
+                world.flush();
+                live.append(&mut reserved);
+            }
+        }
+
+        let mut rows = HashSet::new();
+        for &entity in &live {
+            let Some(EntityLocation {
+                table_id,
+                table_row,
+            }) = world.location(entity)
+            else {
+                panic!("flushed entity {entity} has no location");
+            };
+
+            assert_eq!(table_id, 0, "{entity} is not in the empty table");
+            assert!(rows.insert(table_row), "row {table_row} is used twice");
+
+            let table = world.tables().get(table_id).unwrap();
+            assert_eq!(table.metadata().get(table_row), Some(&entity));
+        }
+        assert_eq!(world.tables().get(0).unwrap().len(), live.len());
+
+        for &entity in &reserved {
+            assert_eq!(world.location(entity), None, "{entity} has a location");
+        }
+    }
+});
@@ -1,4 +1,9 @@
-use super::{ComponentId, Registry};
+#[cfg(feature = "rust-components")]
+use alloc::vec::Vec;
+
+use super::ComponentId;
+#[cfg(feature = "rust-components")]
+use super::{Component, Registry};
 
 /// Describes how to insert the components of a bundle into an entity.
 ///
@@ -24,13 +29,286 @@ unsafe impl InsertBundle for () {
 
 /// Rust types that can be used as a component bundle.
 ///
+/// This trait is implemented for every [`Component`], as well as for tuples of up to 16 bundles.
+/// With the `derive` feature enabled, it can also be implemented for structs whose fields are
+/// bundles themselves using `#[derive(Bundle)]`.
+///
+/// Nested bundles are flattened: `((A, B), C)` is the same bundle as `(A, B, C)`.
+///
 /// # Safety
 ///
-/// Implementors of this trait must ensure that the [`register`] method properly registers the
-/// components that are part of the bundle and return their IDs. The IDs must be unique and
-/// consistent across different invocations of the method.
+/// Implementors of this trait must ensure that the [`take_components`] method yields exactly the
+/// components that [`register_components`] registered, in the same order.
+///
+/// [`register_components`]: Bundle::register_components
+/// [`take_components`]: Bundle::take_components
+#[cfg(feature = "rust-components")]
+pub unsafe trait Bundle: 'static {
+    /// Registers the components that are part of the bundle and pushes their IDs to `out`.
+    fn register_components(registry: &mut Registry, out: &mut Vec<ComponentId>);
+
+    /// Moves the components out of the bundle.
+    ///
+    /// `dst` is called once per component, in the order in which the components have been
+    /// registered by [`register_components`]. When it returns a non-null pointer, the component
+    /// is written to that location. Otherwise, the component is dropped.
+    ///
+    /// # Safety
+    ///
+    /// The non-null pointers returned by `dst` must be valid for writes and properly aligned for
+    /// the component that is being taken.
+    ///
+    /// [`register_components`]: Bundle::register_components
+    unsafe fn take_components(self, dst: &mut impl FnMut() -> *mut u8);
+}
+
+#[cfg(feature = "rust-components")]
+unsafe impl<C: Component> Bundle for C {
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn register_components(registry: &mut Registry, out: &mut Vec<ComponentId>) {
+        out.push(registry.register_rust_component::<C>());
+    }
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    unsafe fn take_components(self, dst: &mut impl FnMut() -> *mut u8) {
+        let ptr = dst();
+        if ptr.is_null() {
+            drop(self);
+        } else {
+            // SAFETY: The caller must provide a pointer that is valid for writes and properly
+            // aligned.
+            unsafe { ptr.cast::<C>().write(self) };
+        }
+    }
+}
+
+#[cfg(feature = "rust-components")]
+macro_rules! impl_bundle_for_tuple {
+    ($($t:ident),*) => {
+        unsafe impl<$($t: Bundle),*> Bundle for ($($t,)*) {
+            #[allow(unused_variables)]
+            #[cfg_attr(feature = "inline-more", inline)]
+            fn register_components(registry: &mut Registry, out: &mut Vec<ComponentId>) {
+                $($t::register_components(registry, out);)*
+            }
+
+            #[allow(non_snake_case, unused_variables)]
+            #[cfg_attr(feature = "inline-more", inline)]
+            unsafe fn take_components(self, dst: &mut impl FnMut() -> *mut u8) {
+                let ($($t,)*) = self;
+                $(unsafe { $t.take_components(dst) };)*
+            }
+        }
+    };
+}
+
+#[cfg(feature = "rust-components")]
+macro_rules! impl_bundle_for_tuples {
+    () => {
+        impl_bundle_for_tuple!();
+    };
+    ($first:ident $(, $rest:ident)*) => {
+        impl_bundle_for_tuple!($first $(, $rest)*);
+        impl_bundle_for_tuples!($($rest),*);
+    };
+}
+
+#[cfg(feature = "rust-components")]
+impl_bundle_for_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+
+/// An [`InsertBundle`] implementation that inserts the components of a Rust [`Bundle`].
 #[cfg(feature = "rust-components")]
-pub unsafe trait Bundle: 'static + InsertBundle {
-    /// Registers the components and the bundle that are associated with this type.
-    fn register_components(registry: &mut Registry) -> Box<[ComponentId]>;
+pub struct RustBundle<'a, B> {
+    /// The bundle to insert.
+    bundle: B,
+    /// The components that the bundle registered, in registration order.
+    components: &'a [ComponentId],
+}
+
+#[cfg(feature = "rust-components")]
+impl<'a, B: Bundle> RustBundle<'a, B> {
+    /// Creates a new [`RustBundle`] instance.
+    ///
+    /// # Safety
+    ///
+    /// `components` must be the list of components that `B` registered. This is the `components`
+    /// field of the [`BundleInfo`] returned for the ID obtained through
+    /// [`Registry::register_rust_bundle`].
+    ///
+    /// [`BundleInfo`]: super::BundleInfo
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub unsafe fn new(bundle: B, components: &'a [ComponentId]) -> Self {
+        Self { bundle, components }
+    }
+}
+
+#[cfg(feature = "rust-components")]
+unsafe impl<B: Bundle> InsertBundle for RustBundle<'_, B> {
+    unsafe fn insert(self, mut dst: impl FnMut(ComponentId) -> *mut u8) {
+        let mut components = self.components.iter();
+
+        // SAFETY: The `components` slice contains exactly one ID for each component that the
+        // bundle yields.
+        unsafe {
+            self.bundle
+                .take_components(&mut || dst(*components.next().unwrap_unchecked()));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "rust-components"))]
+mod test {
+    use alloc::{rc::Rc, vec::Vec};
+    use core::{cell::Cell, ptr::null_mut};
+
+    use super::{Bundle, InsertBundle, RustBundle};
+    use crate::component::{BundleId, Component, Registry};
+
+    struct A(u32);
+    struct B(u64);
+    struct C(Rc<Cell<usize>>);
+
+    impl Component for A {}
+    impl Component for B {}
+    impl Component for C {}
+
+    impl Drop for C {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn register_nested() {
+        let mut r = Registry::new();
+
+        let b = r.register_rust_component::<B>();
+        let id = r.register_rust_bundle::<((A, B), (C,))>();
+        let a = r.register_rust_component::<A>();
+        let c = r.register_rust_component::<C>();
+
+        assert_eq!(&*r.bundles()[id].components, &[a, b, c]);
+        assert_eq!(r.register_rust_bundle::<((A, B), (C,))>(), id);
+    }
+
+    #[test]
+    #[should_panic(expected = "appears more than once")]
+    fn register_duplicate() {
+        let mut r = Registry::new();
+        r.register_rust_bundle::<(A, (B, A))>();
+    }
+
+    /// Inserts `bundle`, which must be made of an `A(1)`, a `B(2)` and a `C`, in that order.
+    ///
+    /// `A` and `B` are written to their slots while `C` is dropped.
+    fn check_insert<T: Bundle>(r: &mut Registry, id: BundleId, bundle: T, drops: &Cell<usize>) {
+        let a = r.register_rust_component::<A>();
+        let b = r.register_rust_component::<B>();
+
+        let mut slot_a = A(0);
+        let mut slot_b = B(0);
+        let mut seen = Vec::new();
+
+        unsafe {
+            RustBundle::new(bundle, &r.bundles()[id].components).insert(|component| {
+                seen.push(component);
+                if component == a {
+                    &mut slot_a as *mut A as *mut u8
+                } else if component == b {
+                    &mut slot_b as *mut B as *mut u8
+                } else {
+                    null_mut()
+                }
+            });
+        }
+
+        assert_eq!(seen, &*r.bundles()[id].components);
+        assert_eq!(slot_a.0, 1);
+        assert_eq!(slot_b.0, 2);
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn insert() {
+        let mut r = Registry::new();
+        let id = r.register_rust_bundle::<(A, (B, C))>();
+
+        let drops = Rc::new(Cell::new(0));
+        let bundle = (A(1), (B(2), C(drops.clone())));
+        check_insert(&mut r, id, bundle, &drops);
+    }
+
+    #[cfg(feature = "derive")]
+    mod derive {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+
+        use super::{check_insert, A, B, C};
+        use crate::component::{Bundle, Registry};
+
+        #[derive(Bundle)]
+        struct Named {
+            a: A,
+            b: B,
+        }
+
+        #[derive(Bundle)]
+        struct Tuple(B, A);
+
+        #[derive(Bundle)]
+        struct Unit;
+
+        #[derive(Bundle)]
+        struct Gen<T> {
+            t: T,
+        }
+
+        #[derive(Bundle)]
+        struct Outer {
+            named: Named,
+            c: C,
+        }
+
+        #[test]
+        fn register_shapes() {
+            let mut r = Registry::new();
+
+            let named = r.register_rust_bundle::<Named>();
+            let tuple = r.register_rust_bundle::<Tuple>();
+            let unit = r.register_rust_bundle::<Unit>();
+            let generic = r.register_rust_bundle::<Gen<(B, A)>>();
+            let a = r.register_rust_component::<A>();
+            let b = r.register_rust_component::<B>();
+
+            assert_eq!(&*r.bundles()[named].components, &[a, b]);
+            assert_eq!(&*r.bundles()[tuple].components, &[b, a]);
+            assert!(r.bundles()[unit].components.is_empty());
+            assert_eq!(&*r.bundles()[generic].components, &[b, a]);
+        }
+
+        #[test]
+        fn register_nested() {
+            let mut r = Registry::new();
+
+            let c = r.register_rust_component::<C>();
+            let id = r.register_rust_bundle::<Outer>();
+            let a = r.register_rust_component::<A>();
+            let b = r.register_rust_component::<B>();
+
+            assert_eq!(&*r.bundles()[id].components, &[a, b, c]);
+        }
+
+        #[test]
+        fn insert() {
+            let mut r = Registry::new();
+            let id = r.register_rust_bundle::<Outer>();
+
+            let drops = Rc::new(Cell::new(0));
+            let bundle = Outer {
+                named: Named { a: A(1), b: B(2) },
+                c: C(drops.clone()),
+            };
+            check_insert(&mut r, id, bundle, &drops);
+        }
+    }
 }
@@ -13,5 +13,37 @@ mod bundle;
 pub use self::bundle::*;
 
 /// A trait for component types.
+///
+/// With the `derive` feature enabled, this trait can be implemented using
+/// `#[derive(Component)]`.
 #[cfg(feature = "rust-components")]
 pub trait Component: 'static {}
+
+#[cfg(feature = "derive")]
+pub use sage_derive::{Bundle, Component};
+
+#[cfg(all(test, feature = "derive"))]
+mod test {
+    use super::{Component, Registry};
+
+    #[derive(Component)]
+    struct Position;
+
+    #[allow(dead_code)]
+    #[derive(Component)]
+    struct Wrap<T>(T);
+
+    #[test]
+    fn derive_component() {
+        let mut r = Registry::new();
+
+        let a = r.register_rust_component::<Position>();
+        let b = r.register_rust_component::<Wrap<u32>>();
+        let c = r.register_rust_component::<Wrap<u64>>();
+
+        assert_eq!(r.register_rust_component::<Position>(), a);
+        assert_ne!(a, b);
+        assert_ne!(b, c);
+        assert!(r.components()[b].name.contains("Wrap<u32>"));
+    }
+}
@@ -3,6 +3,9 @@ use core::alloc::Layout;
 #[cfg(feature = "rust-components")]
 use core::any::TypeId;
 
+#[cfg(feature = "rust-components")]
+use fixedbitset::FixedBitSet;
+
 #[cfg(feature = "rust-components")]
 use super::{Bundle, Component};
 #[cfg(feature = "rust-components")]
@@ -140,6 +143,10 @@ impl Registry {
     ///
     /// If the bundle has already been registered, this function will return the existing bundle
     /// ID.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the same component appears more than once in the bundle.
     #[cfg(feature = "rust-components")]
     pub fn register_rust_bundle<B: Bundle>(&mut self) -> BundleId {
         // FIXME: We can probably avoid.
@@ -148,10 +155,19 @@ impl Registry {
         if self.rust_bundles.contains_key(&type_id) {
             unsafe { *self.rust_bundles.get(&type_id).unwrap_unchecked() }
         } else {
-            let components = B::register_components(self);
+            let mut components = Vec::new();
+            B::register_components(self, &mut components);
+
+            let mut seen = FixedBitSet::with_capacity(self.components.len());
+            for &component in &components {
+                if seen.put(component) {
+                    duplicate_component::<B>(&self.components[component].name);
+                }
+            }
+
             let id = self.register_bundle(BundleInfo {
                 name: core::any::type_name::<B>().into(),
-                components,
+                components: components.into_boxed_slice(),
             });
             self.rust_bundles.insert_unique_unchecked(type_id, id);
             id
@@ -185,3 +201,14 @@ impl Default for Registry {
         Self::new()
     }
 }
+
+#[cfg(feature = "rust-components")]
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn duplicate_component<B>(component: &str) -> ! {
+    panic!(
+        "component `{component}` appears more than once in bundle `{}`",
+        core::any::type_name::<B>(),
+    )
+}
@@ -97,7 +97,7 @@ impl<T> EntityAllocator<T> {
     /// # Returns
     ///
     /// An iterator over the entities that were reserved.
-    pub fn reserve_multiple(&self, count: usize) -> ReserveMultiple<T> {
+    pub fn reserve_multiple(&self, count: usize) -> ReserveMultiple<'_, T> {
         let cursor = self.reserve_raw(count);
         let prev_cursor = unsafe { cursor.unchecked_add(count as isize) };
 
@@ -25,6 +25,10 @@
 
 extern crate alloc;
 
+// Allows the code generated by the derive macros to be used in the tests of this crate.
+#[cfg(all(test, feature = "derive"))]
+extern crate self as sage_ecs;
+
 pub mod component;
 pub mod entity;
 pub mod sparse_set;
@@ -32,3 +36,10 @@ pub mod tables;
 pub mod world;
 
 mod utility;
+
+/// Items used by the code generated by the derive macros.
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec::Vec;
+}
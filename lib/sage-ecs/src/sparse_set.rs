@@ -77,7 +77,7 @@ pub struct VacantEntry<'a, T, I> {
     ///
     /// 1. The vector must already have reserved space for the new value.
     /// 2. The current length of the vector must be strinctly less than the sentinel
-    /// value of the dense index type.
+    ///    value of the dense index type.
     dense: &'a mut Vec<T>,
 }
 
@@ -169,7 +169,7 @@ impl<T, I> SparseSet<T, I> {
 
 impl<T, I: DenseIndex> SparseSet<T, I> {
     /// Returns an entry in the set for the given key.
-    pub fn entry(&mut self, key: usize) -> Entry<T, I> {
+    pub fn entry(&mut self, key: usize) -> Entry<'_, T, I> {
         fn grow_for_key<I: DenseIndex>(sparse: &mut Vec<I>, key: usize) {
             if key > isize::MAX as usize {
                 capacity_overflow();
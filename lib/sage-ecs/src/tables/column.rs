@@ -1,10 +1,7 @@
 use alloc::alloc::handle_alloc_error;
 use core::{alloc::Layout, ptr::NonNull};
 
-use crate::{
-    component::{ComponentId, DropFn},
-    utility::assert_unchecked,
-};
+use crate::{component::DropFn, utility::assert_unchecked};
 
 /// A type erased version `Vec<T>` that is used to store a single type of components within a
 /// `ColumnStorage`.
@@ -49,7 +46,7 @@ impl Column {
     /// - `layout`: The memory layout of the elements stored in this column.
     ///
     /// - `drop_fn`: The drop function of the elements stored in this column. If the elements do
-    /// not need to be dropped, this can be `None`.
+    ///   not need to be dropped, this can be `None`.
     ///
     /// # Safety
     ///
@@ -58,12 +55,17 @@ impl Column {
     pub const fn new(layout: Layout, drop_fn: Option<DropFn>) -> Self {
         // If we are storing a zero-sized type, the capacity of the column is infinite (within the
         // allowed memory limit of a `usize`).
-        let cap = if layout.size() == 0 { usize::MAX } else { 1 };
+        let cap = if layout.size() == 0 { usize::MAX } else { 0 };
+
+        // The data pointer must be aligned even when nothing has been allocated yet.
+        // SAFETY: The alignment of a layout is never zero.
+        let data =
+            unsafe { NonNull::new_unchecked(core::ptr::without_provenance_mut(layout.align())) };
 
         Self {
             layout: pad_layout(layout),
             drop_fn,
-            data: NonNull::dangling(),
+            data,
             cap,
             len: 0,
         }
@@ -133,7 +135,7 @@ impl Column {
     #[cfg_attr(feature = "inline-more", inline)]
     pub fn spare_capacity(&self) -> usize {
         // SAFETY: By invariant, we know that `len <= cap`.
-        unsafe { self.cap.unchecked_sub(self.cap) }
+        unsafe { self.cap.unchecked_sub(self.len) }
     }
 
     /// Grows the capacity of the column to at least `new_capacity`.
@@ -325,7 +327,7 @@ fn capacity_overflow() -> ! {
 mod test {
     use core::alloc::Layout;
 
-    use super::pad_layout;
+    use super::{pad_layout, Column};
 
     #[track_caller]
     fn layout(s: usize, a: usize) -> Layout {
@@ -363,4 +365,36 @@ mod test {
         assert_eq!(l.size(), 8);
         assert_eq!(l.align(), 8);
     }
+
+    #[test]
+    fn test_new_aligned() {
+        let c = Column::new(layout(0, 16), None);
+        assert_eq!(c.as_ptr() as usize % 16, 0);
+
+        let c = Column::new(layout(4, 8), None);
+        assert_eq!(c.as_ptr() as usize % 8, 0);
+        assert_eq!(c.capacity(), 0);
+    }
+
+    #[test]
+    fn test_reserve_push() {
+        let mut c = Column::new(layout(4, 4), None);
+
+        c.reserve(3);
+        assert!(c.capacity() >= 3);
+        let cap = c.capacity();
+
+        unsafe {
+            for i in 0..3 {
+                (c.get_unchecked_mut(i) as *mut u32).write(i as u32);
+            }
+            c.assume_init_push(3);
+        }
+        assert_eq!(c.len(), 3);
+        assert_eq!(c.spare_capacity(), cap - 3);
+
+        c.reserve(cap - 3);
+        assert_eq!(c.capacity(), cap);
+        assert_eq!(unsafe { *(c.get_unchecked(2) as *const u32) }, 2);
+    }
 }
@@ -2,9 +2,14 @@
 
 use alloc::vec::Vec;
 
-mod column;
-use crate::component::Registry;
+#[cfg(feature = "rust-components")]
+use crate::component::{Bundle, RustBundle};
+use crate::{
+    component::{BundleId, Registry},
+    sparse_set::SparseSet,
+};
 
+mod column;
 pub use self::column::*;
 
 mod table;
@@ -44,6 +49,9 @@ pub struct Tables<E> {
     ///
     /// The first element of this vector is always the archetype with no components.
     tables: Vec<Table<E>>,
+    /// Maps the [`BundleId`] of a bundle to the table that stores entities with exactly the
+    /// components of that bundle.
+    bundle_tables: SparseSet<TableId>,
 }
 
 impl<E> Tables<E> {
@@ -51,9 +59,42 @@ impl<E> Tables<E> {
     pub fn new() -> Self {
         Self {
             tables: alloc::vec![Table::new()],
+            bundle_tables: SparseSet::new(),
         }
     }
 
+    /// Returns the table with the provided ID, if it exists.
+    ///
+    /// Id 0 always exists and refers to the table with no components.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn get(&self, table: TableId) -> Option<&Table<E>> {
+        self.tables.get(table)
+    }
+
+    /// Returns the ID of the table that stores entities with exactly the components of the
+    /// provided bundle, creating that table if it does not exist yet.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `bundle` has not been registered in `registry`.
+    pub fn bundle_table(&mut self, registry: &Registry, bundle: BundleId) -> TableId {
+        let tables = &mut self.tables;
+        *self.bundle_tables.entry(bundle).or_insert_with(|| {
+            let components = &registry.bundles()[bundle].components;
+
+            let mut sorted = components.to_vec();
+            sorted.sort_unstable();
+
+            match tables.iter().position(|t| t.components() == sorted) {
+                Some(table_id) => table_id,
+                None => {
+                    tables.push(Table::with_components(registry, components));
+                    tables.len() - 1
+                }
+            }
+        })
+    }
+
     /// Reserves memory for additional entities in the provided table.
     ///
     /// # Panics
@@ -86,6 +127,40 @@ impl<E> Tables<E> {
             }
         }
     }
+
+    /// Spawns an entity with the components of a Rust bundle.
+    ///
+    /// The bundle is registered in `registry` if it was not already.
+    ///
+    /// # Safety
+    ///
+    /// The same `registry` must be used for every call on this [`Tables`] instance that takes
+    /// one. Otherwise, components could be written to columns of a different type.
+    #[cfg(feature = "rust-components")]
+    pub unsafe fn spawn<B: Bundle>(
+        &mut self,
+        registry: &mut Registry,
+        metadata: E,
+        bundle: B,
+    ) -> EntityLocation {
+        let bundle_id = registry.register_rust_bundle::<B>();
+        let table_id = self.bundle_table(registry, bundle_id);
+        let components = &registry.bundles()[bundle_id].components;
+
+        unsafe {
+            let table = self.tables.get_unchecked_mut(table_id);
+            let table_row = table.len();
+
+            // SAFETY: The table has a column for every component of the bundle, created from
+            // the layout registered for that component in `registry`.
+            table.push(metadata, RustBundle::new(bundle, components));
+
+            EntityLocation {
+                table_id,
+                table_row,
+            }
+        }
+    }
 }
 
 impl<E> Default for Tables<E> {
@@ -94,3 +169,86 @@ impl<E> Default for Tables<E> {
         Self::new()
     }
 }
+
+#[cfg(all(test, feature = "rust-components"))]
+mod test {
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    use super::{EntityLocation, Tables};
+    use crate::component::{Component, Registry};
+
+    struct A(u32);
+    struct B(u64);
+    struct C(Rc<Cell<usize>>);
+
+    impl Component for A {}
+    impl Component for B {}
+    impl Component for C {}
+
+    impl Drop for C {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn spawn() {
+        let mut r = Registry::new();
+        let mut tables = Tables::new();
+        let drops = Rc::new(Cell::new(0));
+
+        let (ab, ba, empty, c) = unsafe {
+            (
+                tables.spawn(&mut r, 0, (A(1), B(2))),
+                tables.spawn(&mut r, 1, (B(3), A(4))),
+                tables.spawn(&mut r, 2, ()),
+                tables.spawn(&mut r, 3, C(drops.clone())),
+            )
+        };
+
+        assert_eq!(
+            ab,
+            EntityLocation {
+                table_id: 1,
+                table_row: 0
+            }
+        );
+        assert_eq!(
+            ba,
+            EntityLocation {
+                table_id: 1,
+                table_row: 1
+            }
+        );
+        assert_eq!(
+            empty,
+            EntityLocation {
+                table_id: 0,
+                table_row: 0
+            }
+        );
+        assert_eq!(
+            c,
+            EntityLocation {
+                table_id: 2,
+                table_row: 0
+            }
+        );
+
+        let a = r.register_rust_component::<A>();
+        let b = r.register_rust_component::<B>();
+        let table = tables.get(1).unwrap();
+        assert_eq!(table.metadata(), &[0, 1]);
+        unsafe {
+            let column_a = table.column(a).unwrap();
+            let column_b = table.column(b).unwrap();
+            assert_eq!((*column_a.get_unchecked(1).cast::<A>()).0, 4);
+            assert_eq!((*column_b.get_unchecked(0).cast::<B>()).0, 2);
+        }
+
+        assert_eq!(drops.get(), 0);
+        drop(tables);
+        assert_eq!(drops.get(), 1);
+    }
+}
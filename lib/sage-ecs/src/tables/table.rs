@@ -2,13 +2,15 @@ use alloc::vec::Vec;
 use core::mem::MaybeUninit;
 
 use crate::{
-    component::{ComponentId, InsertBundle},
+    component::{ComponentId, InsertBundle, Registry},
     sparse_set::SparseSet,
     tables::column::Column,
 };
 
 /// Stores a collection with a specific set of components.
 pub struct Table<E> {
+    /// The components stored in this table, sorted by ID.
+    components: Vec<ComponentId>,
     /// The columns that are responsible for storing entity components in this table.
     columns: SparseSet<Column, u8>,
     /// Some metadata associated with the entities in the table.
@@ -19,11 +21,53 @@ impl<E> Table<E> {
     /// Creates a new [`Table`] instance with no entities.
     pub const fn new() -> Self {
         Self {
+            components: Vec::new(),
             columns: SparseSet::new(),
             metadata: Vec::new(),
         }
     }
 
+    /// Creates a new [`Table`] instance with one column for each of the provided components.
+    ///
+    /// The layout and drop function of each column are taken from `registry`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if one of the components has not been registered in `registry`, or
+    /// if more than 254 components are provided.
+    pub fn with_components(registry: &Registry, components: &[ComponentId]) -> Self {
+        if components.len() >= u8::MAX as usize {
+            too_many_components(components.len());
+        }
+
+        let mut columns = SparseSet::new();
+        for &id in components {
+            let info = &registry.components()[id];
+            columns.insert(id, Column::new(info.layout, info.drop_fn));
+        }
+
+        let mut components = components.to_vec();
+        components.sort_unstable();
+
+        Self {
+            components,
+            columns,
+            metadata: Vec::new(),
+        }
+    }
+
+    /// Returns the components stored in this table, sorted by ID.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn components(&self) -> &[ComponentId] {
+        &self.components
+    }
+
+    /// Returns the column that stores the provided component, if the table has one.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn column(&self, component: ComponentId) -> Option<&Column> {
+        self.columns.get(component)
+    }
+
     /// Returns the number of entities in the table.
     #[cfg_attr(feature = "inline-more", inline)]
     pub fn len(&self) -> usize {
@@ -108,3 +152,10 @@ impl<E> Default for Table<E> {
         Self::new()
     }
 }
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn too_many_components(count: usize) -> ! {
+    panic!("a table cannot store {count} components");
+}
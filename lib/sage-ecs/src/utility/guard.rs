@@ -11,6 +11,7 @@ impl<F: FnOnce()> Guard<F> {
     }
 
     /// Defuses the guard and prevents the closure from running when the guard is dropped.
+    #[allow(dead_code)]
     #[cfg_attr(feature = "inline-more", inline)]
     pub fn defuse(self) {
         let mut this = ManuallyDrop::new(self);
@@ -32,6 +33,7 @@ pub fn defer(f: impl FnOnce()) -> Guard<impl FnOnce()> {
 }
 
 /// Returns a guard that aborts the program if the closure is dropped without being defused.
+#[allow(dead_code)]
 #[cfg_attr(feature = "inline-more", inline)]
 pub fn abort_guard() -> Guard<impl FnOnce()> {
     // FIXME: how to abort in no_std?
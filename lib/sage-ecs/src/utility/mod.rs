@@ -3,7 +3,9 @@
 mod guard;
 pub use self::guard::*;
 
+#[cfg(feature = "rust-components")]
 mod noop_hash;
+#[cfg(feature = "rust-components")]
 pub use self::noop_hash::*;
 
 /// Assumes that the provided condition is true.
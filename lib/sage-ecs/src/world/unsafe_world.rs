@@ -38,6 +38,20 @@ impl UnsafeWorld {
         self.entity_allocator.reserve_one()
     }
 
+    /// Returns the location of the provided entity.
+    ///
+    /// Entities that have been reserved but not flushed yet have no location.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn location(&self, entity: Entity) -> Option<EntityLocation> {
+        self.entity_allocator.get(entity).copied()
+    }
+
+    /// Returns the tables that store the entities of this world.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn tables(&self) -> &Tables<Entity> {
+        &self.tables
+    }
+
     /// Spawns a new enity with the given components.
     pub fn spawn(&mut self) {
         self.flush();